@@ -0,0 +1,189 @@
+use super::scalar::Float64;
+
+/// A dense, row-major matrix of [`Float64`] nodes.
+///
+/// Built entirely out of the existing `Float64` graph builders (`add`, `mul`
+/// and `relu`), so
+/// every element of the result carries its own backward closure and a whole
+/// batch can be pushed through the graph with a single [`Matrix::matmul`]
+/// call instead of looping over scalar `Float64` ops by hand.
+#[derive(Debug, Clone)]
+pub struct Matrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<Float64>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, data: Vec<Float64>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Matrix::new: data has {} elements, expected {}x{}",
+            data.len(),
+            rows,
+            cols
+        );
+
+        Self { rows, cols, data }
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> &Float64 {
+        &self.data[r * self.cols + c]
+    }
+
+    /// Builds a matrix of fresh leaf `Float64`s from plain `f64` rows, e.g.
+    /// to turn a `dataset::Reader`'s parsed rows into `Mlp::forward` input.
+    pub fn from_rows(rows: &[Vec<f64>]) -> Matrix {
+        let r = rows.len();
+        let c = rows.first().map_or(0, |row| row.len());
+
+        let mut data = Vec::with_capacity(r * c);
+        for row in rows {
+            assert_eq!(
+                row.len(),
+                c,
+                "Matrix::from_rows: ragged rows ({} vs {})",
+                row.len(),
+                c
+            );
+            data.extend(row.iter().map(|&v| Float64::from(v)));
+        }
+
+        Matrix::new(r, c, data)
+    }
+
+    pub fn matmul(&self, rhs: &Matrix) -> Matrix {
+        assert_eq!(
+            self.cols, rhs.rows,
+            "Matrix::matmul: shape mismatch ({}x{} . {}x{})",
+            self.rows, self.cols, rhs.rows, rhs.cols
+        );
+
+        let mut data = Vec::with_capacity(self.rows * rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut sum = Float64::from(0.0);
+                for k in 0..self.cols {
+                    sum = sum + self.get(i, k) * rhs.get(k, j);
+                }
+                data.push(sum);
+            }
+        }
+
+        Matrix::new(self.rows, rhs.cols, data)
+    }
+
+    /// Elementwise add, broadcasting a single-row `rhs` across every row of
+    /// `self` (used to add a bias row to a batch of activations).
+    pub fn add(&self, rhs: &Matrix) -> Matrix {
+        if self.rows == rhs.rows && self.cols == rhs.cols {
+            let data = self
+                .data
+                .iter()
+                .zip(rhs.data.iter())
+                .map(|(a, b)| a + b)
+                .collect();
+            Matrix::new(self.rows, self.cols, data)
+        } else if rhs.rows == 1 && rhs.cols == self.cols {
+            let mut data = Vec::with_capacity(self.rows * self.cols);
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    data.push(self.get(i, j) + rhs.get(0, j));
+                }
+            }
+            Matrix::new(self.rows, self.cols, data)
+        } else {
+            panic!(
+                "Matrix::add: shape mismatch ({}x{} vs {}x{})",
+                self.rows, self.cols, rhs.rows, rhs.cols
+            );
+        }
+    }
+
+    pub fn relu(&self) -> Matrix {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.data.iter().map(|v| v.relu()).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matmul() {
+        // [1 2]   [5 6]
+        // [3 4] . [7 8]
+        let a = Matrix::new(
+            2,
+            2,
+            vec![
+                Float64::from(1.0),
+                Float64::from(2.0),
+                Float64::from(3.0),
+                Float64::from(4.0),
+            ],
+        );
+        let b = Matrix::new(
+            2,
+            2,
+            vec![
+                Float64::from(5.0),
+                Float64::from(6.0),
+                Float64::from(7.0),
+                Float64::from(8.0),
+            ],
+        );
+
+        let c = a.matmul(&b);
+
+        assert_eq!(c.get(0, 0).borrow().v, 19.0);
+        assert_eq!(c.get(0, 1).borrow().v, 22.0);
+        assert_eq!(c.get(1, 0).borrow().v, 43.0);
+        assert_eq!(c.get(1, 1).borrow().v, 50.0);
+    }
+
+    #[test]
+    fn add_broadcast_bias() {
+        let a = Matrix::new(
+            2,
+            2,
+            vec![
+                Float64::from(1.0),
+                Float64::from(2.0),
+                Float64::from(3.0),
+                Float64::from(4.0),
+            ],
+        );
+        let bias = Matrix::new(1, 2, vec![Float64::from(10.0), Float64::from(20.0)]);
+
+        let c = a.add(&bias);
+
+        assert_eq!(c.get(0, 0).borrow().v, 11.0);
+        assert_eq!(c.get(0, 1).borrow().v, 22.0);
+        assert_eq!(c.get(1, 0).borrow().v, 13.0);
+        assert_eq!(c.get(1, 1).borrow().v, 24.0);
+    }
+
+    #[test]
+    fn from_rows() {
+        let m = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0]]);
+
+        assert_eq!((m.rows, m.cols), (2, 2));
+        assert_eq!(m.get(0, 1).borrow().v, 2.0);
+        assert_eq!(m.get(1, 0).borrow().v, 3.0);
+    }
+
+    #[test]
+    fn relu() {
+        let a = Matrix::new(1, 2, vec![Float64::from(-1.0), Float64::from(2.0)]);
+        let r = a.relu();
+
+        assert_eq!(r.get(0, 0).borrow().v, 0.0);
+        assert_eq!(r.get(0, 1).borrow().v, 2.0);
+    }
+}
@@ -51,11 +51,11 @@ impl Eq for Float64Inner {}
 
 impl Hash for Float64Inner {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        let ptr = format!("{:p}", self);
-
-        // ptr alone is not enough
-        ptr.hash(state);
-        self.children.hash(state);
+        // Identity only: hashing `children` would walk the node's entire
+        // subtree on every `HashSet` lookup, which is what made the
+        // "iterative" backward pass still blow up (O(n^2) hashing, then a
+        // recursive `Drop` on the way out) on a deep graph.
+        std::ptr::hash(self, state);
     }
 }
 
@@ -106,7 +106,7 @@ impl Float64 {
 
     pub fn backward(&self) {
         let mut sorted = VecDeque::<Float64>::new();
-        let mut visited = HashSet::<Float64>::new();
+        let mut visited = HashSet::<*const RefCell<Float64Inner>>::new();
 
         // sort dag in topological order
         self._backward(&mut visited, &mut sorted);
@@ -122,15 +122,39 @@ impl Float64 {
         }
     }
 
-    fn _backward(&self, visited: &mut HashSet<Float64>, sorted: &mut VecDeque<Float64>) {
-        if !visited.contains(self) {
-            visited.insert(self.clone());
+    fn _backward(
+        &self,
+        visited: &mut HashSet<*const RefCell<Float64Inner>>,
+        sorted: &mut VecDeque<Float64>,
+    ) {
+        // explicit-stack DFS so depth is bounded by the heap, not the call stack
+        enum Frame {
+            Enter(Float64),
+            Exit(Float64),
+        }
 
-            for child in &self.borrow().children {
-                child._backward(visited, sorted);
+        let mut stack = vec![Frame::Enter(self.clone())];
+
+        while let Some(frame) = stack.pop() {
+            match frame {
+                Frame::Enter(v) => {
+                    // Keyed by node identity (`Rc` address), not `Float64`'s
+                    // own `PartialEq`, which compares by *value*: distinct
+                    // nodes routinely share a value (every ReLU-clipped
+                    // node is 0.0), and treating those as "the same visited
+                    // node" drops whichever one loses the race, along with
+                    // its whole subtree's gradient.
+                    if visited.insert(Rc::as_ptr(&v.0)) {
+                        // revisit this node once its children are sorted
+                        stack.push(Frame::Exit(v.clone()));
+
+                        for child in &v.borrow().children {
+                            stack.push(Frame::Enter(child.clone()));
+                        }
+                    }
+                }
+                Frame::Exit(v) => sorted.push_front(v),
             }
-
-            sorted.push_front(self.clone())
         }
     }
 }
@@ -164,6 +188,28 @@ impl Hash for Float64 {
     }
 }
 
+impl Drop for Float64 {
+    fn drop(&mut self) {
+        // The default derived drop glue recurses: dropping this node drops
+        // its `children`, which drops theirs, and so on, so a deep chain
+        // overflows the stack on the way out even though traversal in
+        // `_backward` is now iterative. Unlink the subtree with an explicit
+        // work stack instead, only for nodes we are the last owner of.
+        if Rc::strong_count(&self.0) != 1 {
+            return;
+        }
+
+        let mut stack: Vec<Float64> = std::mem::take(&mut self.0.borrow_mut().children);
+        while let Some(node) = stack.pop() {
+            if Rc::strong_count(&node.0) == 1 {
+                stack.append(&mut node.0.borrow_mut().children);
+            }
+            // `node` drops here with its children already detached, so its
+            // own `Drop` impl is O(1) instead of recursing further.
+        }
+    }
+}
+
 impl Neg for Float64 {
     type Output = Float64;
     fn neg(self) -> Self::Output {
@@ -363,4 +409,23 @@ mod tests {
         assert_eq!(y.borrow().g, 2.0);
         assert_eq!(x.borrow().g, 7.0); // L = x * (y + z)
     }
+
+    #[test]
+    fn backward_deep_graph() {
+        // The motivating scenario from the request: a chain this deep
+        // overflows the stack under the old recursive `_backward`, the old
+        // subtree-hashing `Hash for Float64Inner` (hashed on every
+        // visited-set lookup), and the default recursive `Drop` glue alike.
+        const DEPTH: usize = 100_000;
+
+        let mut acc = Float64::from(0.0);
+        let x = Float64::from(1.0);
+        for _ in 0..DEPTH {
+            acc = acc + &x;
+        }
+
+        acc.backward();
+
+        assert_eq!(x.borrow().g, DEPTH as f64);
+    }
 }
@@ -0,0 +1,208 @@
+use crate::autograd::matrix::Matrix;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// How a row's fields are delimited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delim {
+    Whitespace,
+    Csv,
+}
+
+impl Delim {
+    fn split<'a>(&self, line: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+        match self {
+            Delim::Whitespace => Box::new(line.split_whitespace()),
+            Delim::Csv => Box::new(line.split(',').map(str::trim)),
+        }
+    }
+}
+
+/// An error reading or parsing a [`Reader`]'s source.
+#[derive(Debug)]
+pub enum ReaderError {
+    Io(io::Error),
+    InvalidNumber { row: usize, token: String },
+}
+
+impl fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReaderError::Io(e) => write!(f, "{}", e),
+            ReaderError::InvalidNumber { row, token } => {
+                write!(f, "invalid number {:?} in row {}", token, row)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReaderError {}
+
+impl From<io::Error> for ReaderError {
+    fn from(e: io::Error) -> Self {
+        ReaderError::Io(e)
+    }
+}
+
+/// Reads a whitespace- or comma-delimited numeric source into rows, ready to
+/// be split into `(features, labels)` batches for `Mlp::forward`. Row widths
+/// are not checked on reading; `batches` panics when the rows of one batch
+/// differ in width.
+#[derive(Debug)]
+pub struct Reader {
+    rows: Vec<Vec<f64>>,
+}
+
+impl Reader {
+    /// Reads every row from `src`, skipping the first line when
+    /// `skip_header` is set and blank lines throughout.
+    pub fn new<R: BufRead>(src: R, delim: Delim, skip_header: bool) -> Result<Self, ReaderError> {
+        let mut rows = Vec::new();
+
+        for (i, line) in src.lines().enumerate() {
+            let line = line?;
+            if i == 0 && skip_header {
+                continue;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut row = Vec::new();
+            for token in delim.split(&line) {
+                let v = token
+                    .parse::<f64>()
+                    .map_err(|_| ReaderError::InvalidNumber {
+                        row: i,
+                        token: token.to_string(),
+                    })?;
+                row.push(v);
+            }
+            rows.push(row);
+        }
+
+        Ok(Self { rows })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Shuffles row order in place so successive epochs see different
+    /// batches; `seed` makes the shuffle reproducible.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.rows.shuffle(&mut rng);
+    }
+
+    /// Splits rows into `(features, labels)` batches of `batch_size` rows,
+    /// taking the last `n_targets` columns of each row as the label and the
+    /// rest as features, each as a `Matrix` ready for `Mlp::forward`. The
+    /// final batch may hold fewer than `batch_size` rows.
+    pub fn batches(&self, batch_size: usize, n_targets: usize) -> Vec<(Matrix, Matrix)> {
+        assert!(batch_size > 0, "Reader::batches: batch_size must be > 0");
+
+        self.rows
+            .chunks(batch_size)
+            .map(|chunk| {
+                let mut features = Vec::with_capacity(chunk.len());
+                let mut labels = Vec::with_capacity(chunk.len());
+
+                for row in chunk {
+                    assert!(
+                        n_targets <= row.len(),
+                        "Reader::batches: n_targets ({}) exceeds row width ({})",
+                        n_targets,
+                        row.len()
+                    );
+                    let split = row.len() - n_targets;
+                    features.push(row[..split].to_vec());
+                    labels.push(row[split..].to_vec());
+                }
+
+                (Matrix::from_rows(&features), Matrix::from_rows(&labels))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn rows_v(m: &Matrix) -> Vec<Vec<f64>> {
+        (0..m.rows)
+            .map(|r| (0..m.cols).map(|c| m.get(r, c).borrow().v).collect())
+            .collect()
+    }
+
+    #[test]
+    fn whitespace_with_header() {
+        let src = Cursor::new("x1 x2 y\n1.0 2.0 3.0\n4.0 5.0 6.0\n");
+        let reader = Reader::new(src, Delim::Whitespace, true).unwrap();
+
+        assert_eq!(reader.len(), 2);
+
+        let batches = reader.batches(2, 1);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(rows_v(&batches[0].0), vec![vec![1.0, 2.0], vec![4.0, 5.0]]);
+        assert_eq!(rows_v(&batches[0].1), vec![vec![3.0], vec![6.0]]);
+    }
+
+    #[test]
+    fn csv_batches_by_size() {
+        let src = Cursor::new("1,2\n3,4\n5,6\n");
+        let reader = Reader::new(src, Delim::Csv, false).unwrap();
+
+        let batches = reader.batches(2, 1);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].0.rows, 2);
+        assert_eq!(batches[1].0.rows, 1);
+    }
+
+    #[test]
+    fn shuffle_preserves_row_set() {
+        let src = Cursor::new("1,1\n2,2\n3,3\n4,4\n");
+        let mut reader = Reader::new(src, Delim::Csv, false).unwrap();
+
+        reader.shuffle(42);
+
+        let mut rows = reader.rows.clone();
+        rows.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+        assert_eq!(rows, vec![vec![1.0, 1.0], vec![2.0, 2.0], vec![3.0, 3.0], vec![4.0, 4.0]]);
+    }
+
+    #[test]
+    fn invalid_number_is_a_typed_error() {
+        let src = Cursor::new("1,oops\n");
+        let err = Reader::new(src, Delim::Csv, false).unwrap_err();
+
+        assert!(matches!(err, ReaderError::InvalidNumber { row: 0, .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "batch_size must be > 0")]
+    fn batches_rejects_zero_batch_size() {
+        let src = Cursor::new("1,2\n");
+        let reader = Reader::new(src, Delim::Csv, false).unwrap();
+
+        reader.batches(0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "n_targets")]
+    fn batches_rejects_n_targets_wider_than_row() {
+        let src = Cursor::new("1,2\n");
+        let reader = Reader::new(src, Delim::Csv, false).unwrap();
+
+        reader.batches(1, 3);
+    }
+}
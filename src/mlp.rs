@@ -1,17 +1,52 @@
+use crate::autograd::matrix::Matrix;
 use crate::Float64;
 use rand::distributions::{Distribution, Uniform};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub trait Entity {
     fn params(&self) -> Vec<Float64>;
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "NeuronData", into = "NeuronData")]
 pub struct Neuron {
     pub ws: Vec<Float64>,
     pub b: Float64,
     pub lin: bool,
 }
 
+// `Float64` wraps a live `Rc<RefCell<..>>` graph node and can't be
+// serialized directly, so (de)serialization goes through this leaf-value
+// shadow instead: only `v` survives the round trip, and loading rebuilds
+// fresh leaf `Float64`s (g = 0, no children, no `bwd`) from it.
+#[derive(Serialize, Deserialize)]
+struct NeuronData {
+    ws: Vec<f64>,
+    b: f64,
+    lin: bool,
+}
+
+impl From<Neuron> for NeuronData {
+    fn from(n: Neuron) -> Self {
+        NeuronData {
+            ws: n.ws.iter().map(|w| w.borrow().v).collect(),
+            b: n.b.borrow().v,
+            lin: n.lin,
+        }
+    }
+}
+
+impl From<NeuronData> for Neuron {
+    fn from(d: NeuronData) -> Self {
+        Neuron {
+            ws: d.ws.into_iter().map(Float64::from).collect(),
+            b: Float64::from(d.b),
+            lin: d.lin,
+        }
+    }
+}
+
 fn gen_weights(n: usize) -> Vec<Float64> {
     let mut rng = rand::thread_rng();
     let range = Uniform::from(-1.0..=1.0);
@@ -41,12 +76,37 @@ impl Entity for Neuron {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "LayerData", into = "LayerData")]
 pub struct Layer {
     pub n_in: usize,
     pub ns: Vec<Neuron>,
 }
 
+#[derive(Serialize, Deserialize)]
+struct LayerData {
+    n_in: usize,
+    ns: Vec<NeuronData>,
+}
+
+impl From<Layer> for LayerData {
+    fn from(l: Layer) -> Self {
+        LayerData {
+            n_in: l.n_in,
+            ns: l.ns.into_iter().map(NeuronData::from).collect(),
+        }
+    }
+}
+
+impl From<LayerData> for Layer {
+    fn from(d: LayerData) -> Self {
+        Layer {
+            n_in: d.n_in,
+            ns: d.ns.into_iter().map(Neuron::from).collect(),
+        }
+    }
+}
+
 impl Layer {
     pub fn new(n_in: usize, n_out: usize, linear: bool) -> Self {
         let mut ns = Vec::with_capacity(n_out);
@@ -68,10 +128,81 @@ impl Entity for Layer {
     }
 }
 
+impl Layer {
+    /// n_in x n_out weight matrix, column i holding neuron i's weights.
+    fn weights(&self) -> Matrix {
+        let mut data = Vec::with_capacity(self.n_in * self.ns.len());
+        for i in 0..self.n_in {
+            for n in self.ns.iter() {
+                data.push(n.ws[i].clone());
+            }
+        }
+        Matrix::new(self.n_in, self.ns.len(), data)
+    }
+
+    /// 1 x n_out bias row, broadcast over the batch in `forward`.
+    fn bias(&self) -> Matrix {
+        Matrix::new(1, self.ns.len(), self.ns.iter().map(|n| n.b.clone()).collect())
+    }
+
+    /// `inputs` is (batch, n_in); returns (batch, n_out).
+    pub fn forward(&self, inputs: &Matrix) -> Matrix {
+        let mut out = inputs.matmul(&self.weights()).add(&self.bias());
+
+        // Apply ReLU per neuron's own output column rather than per layer:
+        // `Layer::new` always builds a layer with a single uniform `lin`,
+        // but a layer built by hand with mixed flags must still ReLU only
+        // the non-linear neurons' outputs, not every column.
+        for (j, n) in self.ns.iter().enumerate() {
+            if !n.lin {
+                for i in 0..out.rows {
+                    let idx = i * out.cols + j;
+                    out.data[idx] = out.data[idx].relu();
+                }
+            }
+        }
+
+        out
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(from = "MlpData", into = "MlpData")]
 pub struct Mlp {
     ls: Vec<Layer>,
 }
 
+#[derive(Serialize, Deserialize)]
+struct MlpData {
+    ls: Vec<LayerData>,
+}
+
+impl From<Mlp> for MlpData {
+    fn from(m: Mlp) -> Self {
+        MlpData {
+            ls: m.ls.into_iter().map(LayerData::from).collect(),
+        }
+    }
+}
+
+impl From<MlpData> for Mlp {
+    fn from(d: MlpData) -> Self {
+        Mlp {
+            ls: d.ls.into_iter().map(Layer::from).collect(),
+        }
+    }
+}
+
+impl Entity for Mlp {
+    fn params(&self) -> Vec<Float64> {
+        let mut ps = Vec::<Float64>::new();
+        for l in self.ls.iter() {
+            ps.append(&mut l.params());
+        }
+        ps
+    }
+}
+
 impl Mlp {
     pub fn new(n_in: usize, mut layer_out: Vec<usize>) -> Self {
         let sz = layer_out.len();
@@ -87,4 +218,156 @@ impl Mlp {
 
         Mlp { ls }
     }
+
+    /// `inputs` is (batch, n_in); returns (batch, n_out) after the last layer.
+    pub fn forward(&self, inputs: &Matrix) -> Matrix {
+        let mut x = inputs.clone();
+        for l in self.ls.iter() {
+            x = l.forward(&x);
+        }
+        x
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("serialize Mlp")
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        bincode::deserialize(bytes).expect("deserialize Mlp")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("serialize Mlp to JSON")
+    }
+
+    pub fn from_json(json: &str) -> Self {
+        serde_json::from_str(json).expect("deserialize Mlp from JSON")
+    }
+
+    /// Runs one independent forward/backward pass per sample in parallel and
+    /// sums the resulting per-parameter gradients, in the order of
+    /// `self.params()`.
+    ///
+    /// `Float64` is `Rc<RefCell<..>>` and so is `!Send`, which rules out
+    /// sharing one live graph across threads. Instead each rayon worker
+    /// clones the current parameter *values* into its own throwaway graph
+    /// (via `to_bytes`/`from_bytes`, same leaf-rebuilding path used for
+    /// persistence), runs `backward` on it in isolation, and hands back a
+    /// plain `Vec<f64>` of gradients, which is `Send`. The driver then
+    /// folds those vectors together.
+    ///
+    /// The reduction itself is deterministic: the per-sample parallel map
+    /// below is collected back into `samples` order (rayon's `collect`
+    /// preserves index order regardless of how work was split), and the
+    /// driver then folds those per-sample gradient vectors sequentially in
+    /// that same order. Only the independent forward/backward work runs in
+    /// parallel; the summation order — and so the result, bit for bit — does
+    /// not depend on the thread pool or how rayon happened to split the
+    /// batch.
+    pub fn backward_batch(&self, samples: &[Vec<f64>], targets: &[Vec<f64>]) -> Vec<f64> {
+        assert_eq!(samples.len(), targets.len());
+
+        let n_params = self.params().len();
+        let bytes = self.to_bytes();
+
+        let per_sample: Vec<Vec<f64>> = samples
+            .par_iter()
+            .zip(targets.par_iter())
+            .map(|(sample, target)| {
+                let mlp = Mlp::from_bytes(&bytes);
+
+                let input = Matrix::new(
+                    1,
+                    sample.len(),
+                    sample.iter().map(|&x| Float64::from(x)).collect(),
+                );
+                let pred = mlp.forward(&input);
+
+                let loss = pred
+                    .data
+                    .iter()
+                    .zip(target.iter())
+                    .map(|(p, &t)| (p - t).pow(2.0))
+                    .fold(Float64::from(0.0), |acc, v| acc + v);
+
+                loss.backward();
+
+                mlp.params().iter().map(|p| p.borrow().g).collect::<Vec<f64>>()
+            })
+            .collect();
+
+        let mut acc = vec![0.0; n_params];
+        for gs in per_sample.iter() {
+            for (a, g) in acc.iter_mut().zip(gs.iter()) {
+                *a += g;
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params_v(e: &impl Entity) -> Vec<f64> {
+        e.params().iter().map(|p| p.borrow().v).collect()
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let mlp = Mlp::new(3, vec![4, 2]);
+
+        let bytes = mlp.to_bytes();
+        let loaded = Mlp::from_bytes(&bytes);
+
+        assert_eq!(params_v(&mlp), params_v(&loaded));
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let mlp = Mlp::new(3, vec![4, 2]);
+
+        let json = mlp.to_json();
+        let loaded = Mlp::from_json(&json);
+
+        assert_eq!(params_v(&mlp), params_v(&loaded));
+    }
+
+    #[test]
+    fn backward_batch_matches_sequential_sum() {
+        let mlp = Mlp::new(3, vec![4, 2]);
+        let samples = vec![vec![0.1, 0.2, 0.3], vec![-0.5, 0.4, 0.1], vec![1.0, -1.0, 0.5]];
+        let targets = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]];
+
+        let parallel = mlp.backward_batch(&samples, &targets);
+
+        let n_params = mlp.params().len();
+        let bytes = mlp.to_bytes();
+        let mut sequential = vec![0.0; n_params];
+        for (sample, target) in samples.iter().zip(targets.iter()) {
+            let replica = Mlp::from_bytes(&bytes);
+            let input = Matrix::new(
+                1,
+                sample.len(),
+                sample.iter().map(|&x| Float64::from(x)).collect(),
+            );
+            let loss = replica
+                .forward(&input)
+                .data
+                .iter()
+                .zip(target.iter())
+                .map(|(p, &t)| (p - t).pow(2.0))
+                .fold(Float64::from(0.0), |acc, v| acc + v);
+            loss.backward();
+
+            for (acc, p) in sequential.iter_mut().zip(replica.params().iter()) {
+                *acc += p.borrow().g;
+            }
+        }
+
+        // Both accumulate per-sample gradients in the same (sample) order,
+        // so this must match bit for bit, not just within a tolerance.
+        assert_eq!(parallel, sequential);
+    }
 }